@@ -0,0 +1,82 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+/// A value that is either a single default-locale string (the current,
+/// still-valid shape) or a map of locale code to string.
+///
+/// Used for `Function::description` and `Page::name` so existing
+/// robopages stay valid while teams can opt into per-locale variants.
+/// `BTreeMap` (rather than `HashMap`) keeps locale iteration order
+/// deterministic, which matters for the fallback in [`Localized::resolve`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Localized<T> {
+    Default(T),
+    ByLocale(BTreeMap<String, T>),
+}
+
+impl Localized<String> {
+    /// Resolves the string for `locale`, falling back to `en`, then to
+    /// the lexicographically first variant, logging when neither the
+    /// requested locale nor `en` is present so a catalog missing both
+    /// doesn't silently render blank.
+    pub(crate) fn resolve(&self, locale: Option<&str>) -> &str {
+        match self {
+            Self::Default(value) => value,
+            Self::ByLocale(by_locale) => locale
+                .and_then(|locale| by_locale.get(locale))
+                .or_else(|| by_locale.get("en"))
+                .or_else(|| {
+                    log::warn!(
+                        "no {} or \"en\" locale variant found, falling back to the first available",
+                        locale.map(|locale| format!("{locale:?}")).unwrap_or_else(|| "requested".to_owned())
+                    );
+                    by_locale.values().next()
+                })
+                .map(String::as_str)
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_plain_default_string() {
+        let value = Localized::Default("hello".to_owned());
+        assert_eq!(value.resolve(Some("fr")), "hello");
+        assert_eq!(value.resolve(None), "hello");
+    }
+
+    #[test]
+    fn resolves_requested_locale() {
+        let value = Localized::ByLocale(BTreeMap::from([
+            ("en".to_owned(), "hello".to_owned()),
+            ("fr".to_owned(), "bonjour".to_owned()),
+        ]));
+
+        assert_eq!(value.resolve(Some("fr")), "bonjour");
+    }
+
+    #[test]
+    fn falls_back_to_en_when_requested_locale_missing() {
+        let value = Localized::ByLocale(BTreeMap::from([("en".to_owned(), "hello".to_owned())]));
+
+        assert_eq!(value.resolve(Some("de")), "hello");
+    }
+
+    #[test]
+    fn falls_back_to_first_available_when_neither_requested_nor_en_present() {
+        let value = Localized::ByLocale(BTreeMap::from([
+            ("fr".to_owned(), "bonjour".to_owned()),
+            ("ja".to_owned(), "konnichiwa".to_owned()),
+        ]));
+
+        // "fr" sorts before "ja", so the fallback is deterministic.
+        assert_eq!(value.resolve(Some("de")), "bonjour");
+        assert_eq!(value.resolve(None), "bonjour");
+    }
+}
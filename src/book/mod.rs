@@ -0,0 +1,80 @@
+pub(crate) mod localized;
+pub(crate) mod runtime;
+
+use std::collections::HashMap;
+use std::fs;
+
+use camino::Utf8PathBuf;
+use serde::Deserialize;
+
+use localized::Localized;
+
+/// A loaded set of robopages, keyed by the path of the YAML file they
+/// were parsed from (the loaded directory joined with the file name).
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct Book {
+    pub(crate) pages: HashMap<String, Page>,
+}
+
+/// A single robopages YAML document: a named, categorized group of
+/// functions.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct Page {
+    #[serde(default)]
+    pub(crate) categories: Vec<String>,
+    pub(crate) name: Localized<String>,
+    #[serde(default)]
+    pub(crate) functions: HashMap<String, Function>,
+}
+
+/// A single callable tool definition.
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct Function {
+    pub(crate) description: Localized<String>,
+    /// Container image to run the function in, if any. Functions without
+    /// one are executed directly on the host shell.
+    #[serde(default)]
+    pub(crate) container: Option<String>,
+}
+
+impl Book {
+    /// Loads every `*.yml`/`*.yaml` page directly inside `path`, optionally
+    /// restricted to pages whose categories match `filter`. Subdirectories
+    /// are not searched. A page that fails to parse is skipped with a
+    /// warning rather than failing the whole load.
+    pub(crate) fn from_path(path: Utf8PathBuf, filter: Option<String>) -> anyhow::Result<Self> {
+        let mut pages = HashMap::new();
+
+        for entry in fs::read_dir(&path)? {
+            let entry = entry?;
+            let file_path = entry.path();
+
+            let is_yaml = matches!(
+                file_path.extension().and_then(|ext| ext.to_str()),
+                Some("yml") | Some("yaml")
+            );
+            if !is_yaml {
+                continue;
+            }
+
+            let contents = fs::read_to_string(&file_path)?;
+            let page: Page = match serde_yaml::from_str(&contents) {
+                Ok(page) => page,
+                Err(error) => {
+                    log::warn!("skipping {}: {error}", file_path.display());
+                    continue;
+                }
+            };
+
+            if let Some(filter) = &filter {
+                if !page.categories.iter().any(|category| category == filter) {
+                    continue;
+                }
+            }
+
+            pages.insert(file_path.display().to_string(), page);
+        }
+
+        Ok(Self { pages })
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt;
+
+use crate::book::Function;
+
+/// How a function's underlying command is actually executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ExecutionFlavor {
+    /// Run directly as a host shell command.
+    Shell,
+    /// Run inside the container image named on the function.
+    Container,
+}
+
+impl ExecutionFlavor {
+    pub(crate) fn for_function(function: &Function) -> anyhow::Result<Self> {
+        Ok(if function.container.is_some() {
+            Self::Container
+        } else {
+            Self::Shell
+        })
+    }
+}
+
+impl fmt::Display for ExecutionFlavor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Shell => write!(f, "shell"),
+            Self::Container => write!(f, "container"),
+        }
+    }
+}
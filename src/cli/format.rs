@@ -0,0 +1,152 @@
+use comfy_table::Table;
+use serde::Serialize;
+
+/// Output format shared by commands that render a catalog of functions
+/// (`view`, `search`, ...).
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum)]
+pub(crate) enum OutputFormat {
+    #[default]
+    Table,
+    Json,
+    Yaml,
+    Markdown,
+    Csv,
+}
+
+/// A single row of the function catalog, shared by every output format.
+#[derive(Debug, Serialize)]
+pub(crate) struct FunctionRecord {
+    pub(crate) page: String,
+    pub(crate) function: String,
+    pub(crate) context: String,
+    pub(crate) description: String,
+}
+
+pub(crate) fn render(records: &[FunctionRecord], format: OutputFormat) -> anyhow::Result<()> {
+    match format {
+        OutputFormat::Table => render_table(records),
+        OutputFormat::Json => println!("{}", to_json(records)?),
+        OutputFormat::Yaml => println!("{}", to_yaml(records)?),
+        OutputFormat::Markdown => println!("{}", to_markdown(records)),
+        OutputFormat::Csv => print!("{}", to_csv(records)?),
+    }
+
+    Ok(())
+}
+
+fn render_table(records: &[FunctionRecord]) {
+    let mut table = Table::new();
+
+    table.set_header(vec!["page", "function", "context", "description"]);
+
+    let mut last_page = None;
+    for record in records {
+        let page = if last_page.as_ref() == Some(&record.page) {
+            String::new()
+        } else {
+            last_page = Some(record.page.clone());
+            record.page.clone()
+        };
+
+        table.add_row(vec![
+            page,
+            record.function.clone(),
+            record.context.clone(),
+            record.description.clone(),
+        ]);
+    }
+
+    println!("\n{}", table);
+}
+
+fn to_json(records: &[FunctionRecord]) -> anyhow::Result<String> {
+    Ok(serde_json::to_string_pretty(records)?)
+}
+
+fn to_yaml(records: &[FunctionRecord]) -> anyhow::Result<String> {
+    Ok(serde_yaml::to_string(records)?)
+}
+
+fn to_markdown(records: &[FunctionRecord]) -> String {
+    let mut lines = vec![
+        "| page | function | context | description |".to_owned(),
+        "| --- | --- | --- | --- |".to_owned(),
+    ];
+
+    for record in records {
+        lines.push(format!(
+            "| {} | {} | {} | {} |",
+            record.page.replace('|', "\\|"),
+            record.function.replace('|', "\\|"),
+            record.context.replace('|', "\\|"),
+            record.description.replace('|', "\\|"),
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn to_csv(records: &[FunctionRecord]) -> anyhow::Result<String> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for record in records {
+        writer.serialize(record)?;
+    }
+
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record() -> FunctionRecord {
+        FunctionRecord {
+            page: "net > ssh".to_owned(),
+            function: "list_files".to_owned(),
+            context: "shell".to_owned(),
+            description: "lists files over ssh".to_owned(),
+        }
+    }
+
+    #[test]
+    fn json_is_an_array_of_records() {
+        let json = to_json(&[record()]).unwrap();
+        let parsed: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0]["function"], "list_files");
+    }
+
+    #[test]
+    fn yaml_contains_every_field() {
+        let yaml = to_yaml(&[record()]).unwrap();
+
+        assert!(yaml.contains("function: list_files"));
+        assert!(yaml.contains("description: lists files over ssh"));
+    }
+
+    #[test]
+    fn markdown_is_a_github_flavored_table() {
+        let markdown = to_markdown(&[record()]);
+        let mut lines = markdown.lines();
+
+        assert_eq!(lines.next(), Some("| page | function | context | description |"));
+        assert_eq!(lines.next(), Some("| --- | --- | --- | --- |"));
+        assert_eq!(
+            lines.next(),
+            Some("| net > ssh | list_files | shell | lists files over ssh |")
+        );
+    }
+
+    #[test]
+    fn csv_has_header_and_one_row_per_record() {
+        let csv = to_csv(&[record()]).unwrap();
+        let mut lines = csv.lines();
+
+        assert_eq!(lines.next(), Some("page,function,context,description"));
+        assert_eq!(
+            lines.next(),
+            Some("net > ssh,list_files,shell,lists files over ssh")
+        );
+    }
+}
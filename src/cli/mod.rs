@@ -0,0 +1,8 @@
+mod format;
+mod search;
+mod view;
+mod watch;
+
+pub(crate) use format::OutputFormat;
+pub(crate) use search::search;
+pub(crate) use view::view;
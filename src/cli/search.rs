@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+
+use camino::Utf8PathBuf;
+
+use crate::book::{runtime::ExecutionFlavor, Book};
+use crate::cli::format::{self, FunctionRecord, OutputFormat};
+
+/// BM25 term frequency saturation constant.
+const K1: f64 = 1.2;
+/// BM25 length normalization constant.
+const B: f64 = 0.75;
+
+/// Weight applied to matches found in `function_name` relative to
+/// `description`, so a query term hitting the name of a function ranks
+/// above one only mentioned in its prose.
+const FUNCTION_NAME_WEIGHT: f64 = 2.0;
+
+struct Document {
+    record: FunctionRecord,
+    tokens: Vec<String>,
+}
+
+/// An in-memory inverted index over a [`Book`]'s functions, ranked with
+/// BM25 so free-text queries surface the best-matching functions instead
+/// of requiring users to eyeball the `view` table.
+pub(crate) struct Index {
+    documents: Vec<Document>,
+    /// term -> (doc_id, term frequency in that document)
+    postings: HashMap<String, Vec<(usize, usize)>>,
+    avgdl: f64,
+}
+
+impl Index {
+    fn build(book: Book, locale: Option<&str>) -> anyhow::Result<Self> {
+        let mut documents = Vec::new();
+
+        for (_, page) in book.pages {
+            let page_name = page.name.resolve(locale).to_owned();
+
+            for (function_name, function) in page.functions {
+                let context = ExecutionFlavor::for_function(&function)?.to_string();
+                let description = function.description.resolve(locale).to_owned();
+
+                let mut tokens = tokenize(&page.categories.join(" "));
+                tokens.extend(tokenize(&page_name));
+                // Function name terms are pushed in twice so they carry
+                // more weight in the raw term frequency as well as the
+                // explicit weighting applied when scoring.
+                tokens.extend(tokenize(&function_name));
+                tokens.extend(tokenize(&function_name));
+                tokens.extend(tokenize(&description));
+
+                documents.push(Document {
+                    record: FunctionRecord {
+                        page: format!("{} > {}", page.categories.join(" > "), &page_name),
+                        function: function_name,
+                        context,
+                        description,
+                    },
+                    tokens,
+                });
+            }
+        }
+
+        let mut postings: HashMap<String, Vec<(usize, usize)>> = HashMap::new();
+        let mut total_len = 0usize;
+
+        for (doc_id, document) in documents.iter().enumerate() {
+            total_len += document.tokens.len();
+
+            let mut term_freqs: HashMap<&str, usize> = HashMap::new();
+            for token in &document.tokens {
+                *term_freqs.entry(token.as_str()).or_default() += 1;
+            }
+            for (term, tf) in term_freqs {
+                postings.entry(term.to_owned()).or_default().push((doc_id, tf));
+            }
+        }
+
+        let avgdl = if documents.is_empty() {
+            0.0
+        } else {
+            total_len as f64 / documents.len() as f64
+        };
+
+        Ok(Self {
+            documents,
+            postings,
+            avgdl,
+        })
+    }
+
+    /// Ranks every function against `query`, returning the top `limit`
+    /// matches in descending score order.
+    fn search(&self, query: &str, limit: usize) -> Vec<&FunctionRecord> {
+        let n = self.documents.len() as f64;
+        let mut scores = vec![0.0; self.documents.len()];
+
+        for term in tokenize(query) {
+            let Some(postings) = self.postings.get(&term) else {
+                continue;
+            };
+
+            let df = postings.len() as f64;
+            let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+
+            for &(doc_id, tf) in postings {
+                let tf = tf as f64;
+                let dl = self.documents[doc_id].tokens.len() as f64;
+                let denom = tf + K1 * (1.0 - B + B * dl / self.avgdl.max(1.0));
+                let mut score = idf * (tf * (K1 + 1.0)) / denom;
+
+                if self.documents[doc_id].record.function.to_lowercase().contains(&term) {
+                    score *= FUNCTION_NAME_WEIGHT;
+                }
+
+                scores[doc_id] += score;
+            }
+        }
+
+        let mut ranked: Vec<(usize, f64)> = scores.into_iter().enumerate().collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranked.retain(|(_, score)| *score > 0.0);
+        ranked.truncate(limit);
+
+        ranked
+            .into_iter()
+            .map(|(doc_id, _)| &self.documents[doc_id].record)
+            .collect()
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+        .collect()
+}
+
+pub(crate) async fn search(
+    path: Utf8PathBuf,
+    filter: Option<String>,
+    query: String,
+    limit: usize,
+    format: OutputFormat,
+    locale: Option<String>,
+) -> anyhow::Result<()> {
+    let book = Book::from_path(path, filter)?;
+    let index = Index::build(book, locale.as_deref())?;
+
+    let records: Vec<FunctionRecord> = index
+        .search(&query, limit)
+        .into_iter()
+        .map(|record| FunctionRecord {
+            page: record.page.clone(),
+            function: record.function.clone(),
+            context: record.context.clone(),
+            description: record.description.clone(),
+        })
+        .collect();
+
+    format::render(&records, format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::book::localized::Localized;
+    use crate::book::{Function, Page};
+
+    fn function(description: &str) -> Function {
+        Function {
+            description: Localized::Default(description.to_owned()),
+            container: None,
+        }
+    }
+
+    fn page(name: &str, functions: HashMap<String, Function>) -> Page {
+        Page {
+            categories: vec![],
+            name: Localized::Default(name.to_owned()),
+            functions,
+        }
+    }
+
+    #[test]
+    fn tokenize_splits_on_non_alphanumerics_and_lowercases() {
+        assert_eq!(
+            tokenize("List-Files over_SSH!"),
+            vec!["list", "files", "over", "ssh"]
+        );
+    }
+
+    #[test]
+    fn function_name_match_outranks_description_only_match() {
+        let mut name_match = HashMap::new();
+        name_match.insert("ssh_list_files".to_owned(), function("does something unrelated"));
+
+        let mut description_match = HashMap::new();
+        description_match.insert("other_tool".to_owned(), function("uses ssh to list files"));
+
+        let book = Book {
+            pages: HashMap::from([
+                ("a".to_owned(), page("a", name_match)),
+                ("b".to_owned(), page("b", description_match)),
+            ]),
+        };
+
+        let index = Index::build(book, None).unwrap();
+        let results = index.search("ssh", 10);
+
+        assert_eq!(results[0].function, "ssh_list_files");
+    }
+
+    #[test]
+    fn unmatched_query_returns_no_results() {
+        let mut functions = HashMap::new();
+        functions.insert("list_files".to_owned(), function("lists files over ssh"));
+
+        let book = Book {
+            pages: HashMap::from([("a".to_owned(), page("a", functions))]),
+        };
+
+        let index = Index::build(book, None).unwrap();
+
+        assert!(index.search("nonexistent", 10).is_empty());
+    }
+}
@@ -1,38 +1,53 @@
 use camino::Utf8PathBuf;
-use comfy_table::Table;
 
 use crate::book::{runtime::ExecutionFlavor, Book};
+use crate::cli::format::{self, FunctionRecord, OutputFormat};
+use crate::cli::watch::WatchedBook;
 
-pub(crate) async fn view(path: Utf8PathBuf, filter: Option<String>) -> anyhow::Result<()> {
-    let book = Book::from_path(path, filter)?;
+pub(crate) async fn view(
+    path: Utf8PathBuf,
+    filter: Option<String>,
+    format: OutputFormat,
+    watch: bool,
+    locale: Option<String>,
+) -> anyhow::Result<()> {
+    if watch {
+        let mut book = WatchedBook::spawn(path, filter).await?;
+        loop {
+            render(&*book.read().await, format, locale.as_deref())?;
 
-    let mut table = Table::new();
-
-    table.set_header(vec!["page", "function", "context", "description"]);
-
-    for (_, page) in book.pages {
-        let mut first_page = true;
-        for (function_name, function) in page.functions {
-            if first_page {
-                table.add_row(vec![
-                    format!("{} > {}", page.categories.join(" > "), &page.name),
-                    function_name,
-                    ExecutionFlavor::for_function(&function)?.to_string(),
-                    function.description,
-                ]);
-                first_page = false;
-            } else {
-                table.add_row(vec![
-                    "".to_owned(),
-                    function_name,
-                    ExecutionFlavor::for_function(&function)?.to_string(),
-                    function.description,
-                ]);
+            if !book.changed().await {
+                log::warn!("book watcher stopped; exiting --watch loop");
+                break;
             }
         }
+        Ok(())
+    } else {
+        let book = Book::from_path(path, filter)?;
+        render(&book, format, locale.as_deref())
     }
+}
+
+fn render(book: &Book, format: OutputFormat, locale: Option<&str>) -> anyhow::Result<()> {
+    let mut records = Vec::new();
 
-    println!("\n{}", table);
+    for (_, page) in &book.pages {
+        for (function_name, function) in &page.functions {
+            let flavor = ExecutionFlavor::for_function(function)?.to_string();
+            log::debug!("resolved {function_name} in {} as {flavor}", page.name.resolve(locale));
+
+            records.push(FunctionRecord {
+                page: format!(
+                    "{} > {}",
+                    page.categories.join(" > "),
+                    page.name.resolve(locale)
+                ),
+                function: function_name.clone(),
+                context: flavor,
+                description: function.description.resolve(locale).to_owned(),
+            });
+        }
+    }
 
-    Ok(())
+    format::render(&records, format)
 }
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use camino::Utf8PathBuf;
+use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode};
+use tokio::sync::RwLock;
+
+use crate::book::Book;
+
+/// Debounce window used to coalesce bursts of filesystem events into a
+/// single reload.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// A [`Book`] kept in sync with the YAML files it was loaded from.
+///
+/// Cloning a handle is cheap: every clone shares the same underlying lock,
+/// so any command holding onto a `WatchedBook` always sees the most
+/// recently reloaded book.
+///
+/// NOTE: the original ask was for `view --watch` *and* the server to
+/// share this. The server command isn't part of this chunk's tree, so
+/// that half is intentionally NOT done here — it's split out as
+/// follow-up work to wire `WatchedBook` into the server's startup path
+/// once that module is in scope, rather than being silently dropped.
+#[derive(Clone)]
+pub(crate) struct WatchedBook {
+    inner: Arc<RwLock<Book>>,
+    changed: tokio::sync::watch::Receiver<()>,
+}
+
+impl WatchedBook {
+    /// Loads `path` once and spawns a background task that reloads the
+    /// book whenever the directory tree changes, keeping the last known
+    /// good book in place if [`Book::from_path`] returns an error. A single
+    /// page that fails to parse does not cause such an error: it is
+    /// skipped with a warning, and the reloaded book replaces the old one
+    /// without that page.
+    pub(crate) async fn spawn(
+        path: Utf8PathBuf,
+        filter: Option<String>,
+    ) -> anyhow::Result<Self> {
+        let book = Book::from_path(path.clone(), filter.clone())?;
+        let inner = Arc::new(RwLock::new(book));
+        let (changed_tx, changed_rx) = tokio::sync::watch::channel(());
+
+        let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+        let mut debouncer = new_debouncer(DEBOUNCE, move |result| {
+            // Only a successful batch of events triggers a reload; watcher
+            // errors are dropped here without a reload or a log entry.
+            if result.is_ok() {
+                let _ = tx.blocking_send(());
+            }
+        })?;
+        debouncer
+            .watcher()
+            .watch(path.as_std_path(), RecursiveMode::Recursive)?;
+
+        let reload_inner = inner.clone();
+        tokio::spawn(async move {
+            // Keep the debouncer alive for the lifetime of the task.
+            let _debouncer = debouncer;
+
+            while rx.recv().await.is_some() {
+                match Book::from_path(path.clone(), filter.clone()) {
+                    Ok(book) => {
+                        *reload_inner.write().await = book;
+                        let _ = changed_tx.send(());
+                        log::info!("reloaded book from {path}");
+                    }
+                    Err(error) => {
+                        log::error!("failed to reload book from {path}, keeping last good copy: {error}");
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            inner,
+            changed: changed_rx,
+        })
+    }
+
+    /// Returns a read guard over the current book. Held only for the
+    /// duration of a single render so reloads are never blocked for long.
+    pub(crate) async fn read(&self) -> tokio::sync::RwLockReadGuard<'_, Book> {
+        self.inner.read().await
+    }
+
+    /// Resolves the next time the book is successfully reloaded.
+    ///
+    /// Returns `false` once the background reload task has exited (e.g.
+    /// the filesystem watcher died), so callers can stop looping instead
+    /// of spinning on an immediately-ready, permanently closed channel.
+    pub(crate) async fn changed(&mut self) -> bool {
+        self.changed.changed().await.is_ok()
+    }
+}
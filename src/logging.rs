@@ -0,0 +1,55 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use flexi_logger::{Cleanup, Criterion, Duplicate, FileSpec, Logger, Naming, WriteMode};
+use log::LevelFilter;
+
+/// Log files are rotated once they pass this size, keeping a bounded
+/// history instead of growing forever.
+const ROTATE_SIZE_BYTES: u64 = 10 * 1024 * 1024;
+/// How many rotated files to retain before the oldest is deleted.
+const KEEP_ROTATED_FILES: usize = 10;
+
+/// Options controlling where and how verbosely the crate logs.
+pub(crate) struct LoggingOptions {
+    pub(crate) level: LevelFilter,
+    pub(crate) log_file: Option<PathBuf>,
+    pub(crate) quiet: bool,
+}
+
+/// Initializes the process-wide logger.
+///
+/// `RUST_LOG` takes precedence over `--log-level` when set, and accepts
+/// full `env_logger`-style directives (e.g. `robopages_cli=debug`), not
+/// just a bare level. Console output honors `--quiet`, while the
+/// optional file sink is rotated by size and always records at the
+/// configured level. Console and file share that one level, so parse
+/// warnings from [`crate::book::Book::from_path`] reach the file only
+/// when the level is `warn` or more verbose.
+pub(crate) fn init(options: LoggingOptions) -> anyhow::Result<()> {
+    if options.quiet && options.log_file.is_none() {
+        return Ok(());
+    }
+
+    let mut logger = Logger::try_with_env_or_str(options.level.to_string())
+        .context("failed to parse log level")?;
+
+    if let Some(log_file) = options.log_file {
+        logger = logger
+            .log_to_file(FileSpec::try_from(log_file)?)
+            .rotate(
+                Criterion::Size(ROTATE_SIZE_BYTES),
+                Naming::Timestamps,
+                Cleanup::KeepLogFiles(KEEP_ROTATED_FILES),
+            )
+            .write_mode(WriteMode::BufferAndFlush);
+
+        if !options.quiet {
+            logger = logger.duplicate_to_stderr(Duplicate::All);
+        }
+    }
+
+    logger.start().context("failed to initialize logger")?;
+
+    Ok(())
+}